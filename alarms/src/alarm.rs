@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString};
+
+/// Every metric railway-alarms knows how to watch.
+///
+/// The `Display`/`EnumString` impls (derived as `SCREAMING_SNAKE_CASE`) double as the env var
+/// name used to configure each alarm, e.g. `Alarm::CpuVcpus` is read from `CPU_VCPUS`.
+#[derive(
+    Display, EnumIter, EnumString, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum Alarm {
+    /// vCPUs the service is consuming, bounded on either side with a single `min..max` band
+    /// (e.g. `CPU_VCPUS=0.5..4`) rather than a separate lower- and upper-limit alarm.
+    CpuVcpus,
+    /// Percentage of sampling periods the CPU cgroup controller throttled the service in,
+    /// computed as `(nr_throttled_delta / nr_periods_delta) * 100` between consecutive samples
+    /// of `cpu.stat` (`nr_periods`/`nr_throttled` in cgroup v1, `throttled_usec` in v2).
+    CpuThrottledPercent,
+    /// Current resident memory usage in bytes, read from the memory cgroup controller's
+    /// `memory.current` (compared against `memory.max`/the configured threshold).
+    MemoryUpperLimitBytes,
+    /// Current swap usage in bytes, read the same way as [`Alarm::MemoryUpperLimitBytes`] but
+    /// from the controller's swap accounting.
+    MemorySwapUpperLimitBytes,
+    /// Number of times the kernel OOM-killed a process in the service's cgroup, computed as the
+    /// delta of the `oom_kill` counter in `memory.events` between consecutive samples.
+    OomKillCount,
+    HealthCheckFailed,
+}
@@ -5,9 +5,92 @@ use std::collections::HashMap;
 use strum::IntoEnumIterator;
 use tracing::{warn, debug};
 
+/// The parsed value a given [`Alarm`] is compared against, typed per variant so the env var
+/// machinery in [`optional`] doesn't need to special-case non-numeric alarms.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum AlarmThreshold {
+    Float(f64),
+    /// A `min..max` band, either side optional, e.g. `0.5..4`, `..4` or `0.5..`. The alarm fires
+    /// when the sampled value leaves `[min, max]`.
+    MinMax {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    HealthCheckEndpoint(String),
+}
+
+impl AlarmThreshold {
+    /// Parses the env var `value` for a numeric alarm, accepting either a plain float or a
+    /// `min..max` range (either bound optional).
+    fn parse_numeric(value: &str, alarm: Alarm) -> Result<Self> {
+        match value.split_once("..") {
+            Some((min, max)) => {
+                let min = (!min.is_empty())
+                    .then(|| {
+                        min.parse::<f64>()
+                            .map_err(|err| Error::ParseFloatWithMetadata(err, alarm.to_string()))
+                    })
+                    .transpose()?;
+                let max = (!max.is_empty())
+                    .then(|| {
+                        max.parse::<f64>()
+                            .map_err(|err| Error::ParseFloatWithMetadata(err, alarm.to_string()))
+                    })
+                    .transpose()?;
+
+                if min.is_none() && max.is_none() {
+                    return Err(Error::InvalidRange(
+                        alarm.to_string(),
+                        "range has no bounds, set a min, a max, or both",
+                    ));
+                }
+                if let (Some(min), Some(max)) = (min, max) {
+                    if min > max {
+                        return Err(Error::InvalidRange(
+                            alarm.to_string(),
+                            "min must not be greater than max",
+                        ));
+                    }
+                }
+
+                Ok(AlarmThreshold::MinMax { min, max })
+            }
+            None => Ok(AlarmThreshold::Float(
+                value
+                    .parse::<f64>()
+                    .map_err(|err| Error::ParseFloatWithMetadata(err, alarm.to_string()))?,
+            )),
+        }
+    }
+
+    /// Returns the threshold as a float, or `None` if this alarm's value isn't a plain float.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            AlarmThreshold::Float(value) => Some(*value),
+            AlarmThreshold::MinMax { .. } | AlarmThreshold::HealthCheckEndpoint(_) => None,
+        }
+    }
+
+    /// Returns the threshold as a `(min, max)` band, or `None` if this alarm's value isn't a range.
+    pub fn as_min_max(&self) -> Option<(Option<f64>, Option<f64>)> {
+        match self {
+            AlarmThreshold::MinMax { min, max } => Some((*min, *max)),
+            AlarmThreshold::Float(_) | AlarmThreshold::HealthCheckEndpoint(_) => None,
+        }
+    }
+
+    /// Returns the threshold as a health check endpoint, or `None` if this alarm's value isn't one.
+    pub fn as_health_check_endpoint(&self) -> Option<&str> {
+        match self {
+            AlarmThreshold::Float(_) | AlarmThreshold::MinMax { .. } => None,
+            AlarmThreshold::HealthCheckEndpoint(endpoint) => Some(endpoint),
+        }
+    }
+}
+
 #[derive(Getters, Serialize, Deserialize, Clone, Debug)]
 pub struct AlarmConfig {
-    value: String,
+    value: AlarmThreshold,
     #[copy]
     period_minutes: u16,
     #[copy]
@@ -20,11 +103,20 @@ const DEFAULT_PERIOD_MINUTES: u16 = 1;
 const MIN_PERIOD_MINUTES: u16 = 1;
 
 const DEFAULT_DATA_POINTS: u16 = 5;
-const MIN_DATA_POINTS: u16 = 1;
 
 const DEFAULT_DATA_POINTS_TO_ALARM: u16 = 3;
 const MIN_DATA_POINTS_TO_ALARM: u16 = 1;
 
+/// Env var prefixes that used to name their own `Alarm` variant before `CpuLowerLimitVcpus`/
+/// `CpuUpperLimitVcpus` were consolidated into [`Alarm::CpuVcpus`]'s `min..max` band. Checked by
+/// [`optional`] when the canonical name isn't set, so existing deployments don't have their alarm
+/// silently disappear; the `_PERIOD_MINUTES`/`_DATA_POINTS`/`_DATA_POINTS_TO_ALARM` overrides are
+/// read under the same (deprecated) prefix as `value`.
+const DEPRECATED_ALIASES: &[(&str, Alarm)] = &[
+    ("CPU_LOWER_LIMIT_VCPUS", Alarm::CpuVcpus),
+    ("CPU_UPPER_LIMIT_VCPUS", Alarm::CpuVcpus),
+];
+
 pub fn required() -> Result<(String, String, String, String)> {
     let railway_api_token = std::env::var("RAILWAY_API_TOKEN")
         .map_err(|_| Error::MissingEnvVar("RAILWAY_API_TOKEN"))?;
@@ -75,72 +167,159 @@ pub fn optional() -> Result<HashMap<Alarm, AlarmConfig>> {
         .unwrap_or(DEFAULT_DATA_POINTS_TO_ALARM);
 
     let mut configs = HashMap::new();
+    let mut errors = Vec::new();
     for alarm in Alarm::iter() {
-        if let Some(value) = std::env::var(alarm.to_string()).ok() {
-            // Short term solution to allow both alarm types with the same env var machinery
-            // The correct solution is having a AlarmWithPaylaod type that adds a value tuple to each variant of Alarm
-            if alarm != Alarm::HealthCheckFailed {
-                if let Err(err) = value.parse::<f64>() {
-                    return Err(Error::ParseFloatWithMetadata(err, alarm.to_string()));
+        let canonical_env_name = alarm.to_string();
+        let found = std::env::var(&canonical_env_name)
+            .ok()
+            .map(|value| (canonical_env_name.clone(), value))
+            .or_else(|| {
+                DEPRECATED_ALIASES
+                    .iter()
+                    .filter(|(_, aliased)| *aliased == alarm)
+                    .find_map(|(alias, _)| {
+                        std::env::var(alias)
+                            .ok()
+                            .map(|value| (alias.to_string(), value))
+                    })
+            });
+
+        let Some((env_name, value)) = found else {
+            continue;
+        };
+        if env_name != canonical_env_name {
+            warn!("{env_name} is deprecated, use {canonical_env_name} instead");
+        }
+
+        match parse_alarm_config(
+            alarm,
+            &env_name,
+            value,
+            default_period_minutes,
+            default_data_points,
+            default_data_points_to_alarm,
+        ) {
+            Ok(config) => {
+                // A plain zero threshold leaves the alarm disabled
+                if config.value.as_float() != Some(0.) {
+                    configs.insert(alarm, config);
                 }
             }
+            Err(err) => errors.push(err),
+        }
+    }
 
-            let period_minutes_env_name = format!("{alarm}_PERIOD_MINUTES");
-            let mut period_minutes = std::env::var(&period_minutes_env_name)
-                .ok()
-                .map(|value| value.parse::<u16>())
-                .transpose()
-                .map_err(|err| Error::ParseIntWithMetadata(err, period_minutes_env_name.clone()))?
-                .unwrap_or(default_period_minutes);
-            if period_minutes < MIN_PERIOD_MINUTES {
-                period_minutes = MIN_PERIOD_MINUTES;
-                warn!("{period_minutes_env_name} can't be below {MIN_PERIOD_MINUTES}, setting it to {MIN_PERIOD_MINUTES}");
-            }
+    if let Err(validation_errors) = validate(&mut configs) {
+        errors.extend(validation_errors);
+    }
 
-            let data_points_env_name = format!("{alarm}_DATA_POINTS");
-            let mut data_points = std::env::var(&data_points_env_name)
-                .ok()
-                .map(|value| value.parse::<u16>())
-                .transpose()
-                .map_err(|err| Error::ParseIntWithMetadata(err, data_points_env_name.clone()))?
-                .unwrap_or(default_data_points);
-            if data_points < MIN_DATA_POINTS {
-                data_points = MIN_DATA_POINTS;
-                warn!("{data_points_env_name} can't be below {MIN_DATA_POINTS}, setting it to {MIN_DATA_POINTS}");
-            }
+    if !errors.is_empty() {
+        return Err(Error::Multiple(errors));
+    }
 
-            let data_points_to_alarm_env_name = format!("{alarm}_DATA_POINTS_TO_ALARM");
-            let mut data_points_to_alarm = std::env::var(&data_points_to_alarm_env_name)
-                .ok()
-                .map(|value| value.parse::<u16>())
-                .transpose()
-                .map_err(|err| {
-                    Error::ParseIntWithMetadata(err, data_points_to_alarm_env_name.clone())
-                })?
-                .unwrap_or(default_data_points_to_alarm);
-            if data_points_to_alarm < MIN_DATA_POINTS_TO_ALARM {
-                data_points_to_alarm = MIN_DATA_POINTS_TO_ALARM;
-                warn!("{data_points_to_alarm_env_name} can't be below {MIN_DATA_POINTS_TO_ALARM}, setting it to {MIN_DATA_POINTS_TO_ALARM}");
-            }
+    debug!("Configs: {configs:#?}");
+    Ok(configs)
+}
+
+/// Parses a single alarm's env vars (`value` and the `_PERIOD_MINUTES`/`_DATA_POINTS`/
+/// `_DATA_POINTS_TO_ALARM` overrides), falling back to the given defaults when an override isn't
+/// set. `env_name` is the env var `value` was actually read from (the alarm's canonical name, or
+/// one of `DEPRECATED_ALIASES`), so the overrides are looked up under the same prefix. Kept as its
+/// own function so [`optional`] can collect every alarm's parse errors instead of bailing out on
+/// the first one.
+fn parse_alarm_config(
+    alarm: Alarm,
+    env_name: &str,
+    value: String,
+    default_period_minutes: u16,
+    default_data_points: u16,
+    default_data_points_to_alarm: u16,
+) -> Result<AlarmConfig> {
+    let value = match alarm {
+        Alarm::HealthCheckFailed => AlarmThreshold::HealthCheckEndpoint(value),
+        _ => AlarmThreshold::parse_numeric(&value, alarm)?,
+    };
 
-            configs.insert(
-                alarm,
-                AlarmConfig {
-                    value,
-                    period_minutes,
-                    data_points,
-                    data_points_to_alarm,
-                },
+    let period_minutes_env_name = format!("{env_name}_PERIOD_MINUTES");
+    let mut period_minutes = std::env::var(&period_minutes_env_name)
+        .ok()
+        .map(|value| value.parse::<u16>())
+        .transpose()
+        .map_err(|err| Error::ParseIntWithMetadata(err, period_minutes_env_name.clone()))?
+        .unwrap_or(default_period_minutes);
+    if period_minutes < MIN_PERIOD_MINUTES {
+        period_minutes = MIN_PERIOD_MINUTES;
+        warn!("{period_minutes_env_name} can't be below {MIN_PERIOD_MINUTES}, setting it to {MIN_PERIOD_MINUTES}");
+    }
+
+    let data_points_env_name = format!("{env_name}_DATA_POINTS");
+    let data_points = std::env::var(&data_points_env_name)
+        .ok()
+        .map(|value| value.parse::<u16>())
+        .transpose()
+        .map_err(|err| Error::ParseIntWithMetadata(err, data_points_env_name.clone()))?
+        .unwrap_or(default_data_points);
+    // A zero data_points window can never satisfy data_points_to_alarm; rejected
+    // outright by `validate` below rather than silently clamped up here.
+
+    let data_points_to_alarm_env_name = format!("{env_name}_DATA_POINTS_TO_ALARM");
+    let mut data_points_to_alarm = std::env::var(&data_points_to_alarm_env_name)
+        .ok()
+        .map(|value| value.parse::<u16>())
+        .transpose()
+        .map_err(|err| Error::ParseIntWithMetadata(err, data_points_to_alarm_env_name.clone()))?
+        .unwrap_or(default_data_points_to_alarm);
+    if data_points_to_alarm < MIN_DATA_POINTS_TO_ALARM {
+        data_points_to_alarm = MIN_DATA_POINTS_TO_ALARM;
+        warn!("{data_points_to_alarm_env_name} can't be below {MIN_DATA_POINTS_TO_ALARM}, setting it to {MIN_DATA_POINTS_TO_ALARM}");
+    }
+
+    Ok(AlarmConfig {
+        value,
+        period_minutes,
+        data_points,
+        data_points_to_alarm,
+    })
+}
+
+/// Validates the assembled configs, clamping `data_points_to_alarm` to at most `data_points`
+/// (the M-of-N invariant) and rejecting a zero `data_points` window outright, since that can
+/// never satisfy any `data_points_to_alarm`. Unlike `required()`, which fails on the first bad env
+/// var, this accumulates every problem so operators can fix them all at once.
+pub fn validate(configs: &mut HashMap<Alarm, AlarmConfig>) -> std::result::Result<(), Vec<Error>> {
+    let mut errors = Vec::new();
+
+    for (alarm, config) in configs.iter_mut() {
+        if config.data_points == 0 {
+            errors.push(Error::ZeroDataPointsWindow(alarm.to_string()));
+            continue;
+        }
+
+        if config.data_points_to_alarm > config.data_points {
+            warn!(
+                "{alarm} can't alarm on more data points ({}) than it samples ({}), clamping data_points_to_alarm to data_points",
+                config.data_points_to_alarm, config.data_points
             );
+            config.data_points_to_alarm = config.data_points;
         }
     }
-    debug!("Configs: {configs:#?}");
-    Ok(configs)
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+pub fn get() -> Result<HashMap<Alarm, AlarmConfig>> {
+    optional()
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{AlarmConfig, AlarmThreshold};
     use crate::Alarm;
+    use std::collections::HashMap;
     use strum::IntoEnumIterator;
 
     #[test]
@@ -152,8 +331,12 @@ mod tests {
 
         let config = super::get().expect("unable to get config from env vars");
         assert_eq!(config.len(), Alarm::iter().count());
-        for (_alarm, config) in config {
-            assert_eq!(config.value(), 3.);
+        for (alarm, config) in config {
+            if alarm == Alarm::HealthCheckFailed {
+                assert_eq!(config.value().as_health_check_endpoint(), Some("3"));
+            } else {
+                assert_eq!(config.value().as_float(), Some(3.));
+            }
         }
 
         for alarm in Alarm::iter() {
@@ -161,39 +344,44 @@ mod tests {
         }
 
         // Parse Error
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS", "a");
+        std::env::set_var("CPU_VCPUS", "a");
         assert!(super::get().is_err());
 
         // Zero
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS", "0");
+        std::env::set_var("CPU_VCPUS", "0");
         let config = super::get().expect("unable to get config from env vars");
         assert!(config.is_empty());
 
         // Default
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS", "5.");
+        std::env::set_var("CPU_VCPUS", "5.");
         let config = super::get().expect("unable to get config from env vars");
         assert_eq!(config.len(), 1);
 
-        let cpu_lower = config
-            .get(&Alarm::CpuLowerLimitVcpus)
-            .expect("no lower limit for cpu found");
-        assert_eq!(cpu_lower.value(), 5.);
-        assert_eq!(cpu_lower.period_minutes(), 1);
-        assert_eq!(cpu_lower.data_points(), 5);
-        assert_eq!(cpu_lower.data_points_to_alarm(), 3);
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_float(), Some(5.));
+        assert_eq!(cpu_vcpus.period_minutes(), 1);
+        assert_eq!(cpu_vcpus.data_points(), 5);
+        assert_eq!(cpu_vcpus.data_points_to_alarm(), 3);
 
         // Clipped
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS", "1");
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS_PERIOD_MINUTES", "0");
+        std::env::set_var("CPU_VCPUS", "1");
+        std::env::set_var("CPU_VCPUS_PERIOD_MINUTES", "0");
 
         let config = super::get().expect("unable to get config from env vars");
         assert_eq!(config.len(), 1);
 
-        let cpu_lower = config
-            .get(&Alarm::CpuLowerLimitVcpus)
-            .expect("no lower limit for cpu found");
-        assert_eq!(cpu_lower.value(), 1.);
-        assert_eq!(cpu_lower.period_minutes(), 1);
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_float(), Some(1.));
+        assert_eq!(cpu_vcpus.period_minutes(), 1);
+
+        // A zero data_points window is rejected by validate() rather than clamped
+        std::env::set_var("CPU_VCPUS_DATA_POINTS", "0");
+        assert!(super::get().is_err());
+        std::env::remove_var("CPU_VCPUS_DATA_POINTS");
 
         // Custom
         // Setting env vars affects the whole process, so we avoid doing that from many tests
@@ -201,29 +389,130 @@ mod tests {
         std::env::set_var("DATA_POINTS", "2");
         std::env::set_var("DATA_POINTS_TO_ALARM", "2");
 
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS", "1");
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS_PERIOD_MINUTES", "5");
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS_DATA_POINTS", "6");
-        std::env::set_var("CPU_LOWER_LIMIT_VCPUS_DATA_POINTS_TO_ALARM", "1");
+        std::env::set_var("CPU_VCPUS", "1");
+        std::env::set_var("CPU_VCPUS_PERIOD_MINUTES", "5");
+        std::env::set_var("CPU_VCPUS_DATA_POINTS", "6");
+        std::env::set_var("CPU_VCPUS_DATA_POINTS_TO_ALARM", "1");
 
-        std::env::set_var("CPU_UPPER_LIMIT_VCPUS", "4");
+        std::env::set_var("MEMORY_UPPER_LIMIT_BYTES", "4");
         let config = super::get().expect("unable to get config from env vars");
         assert_eq!(config.len(), 2);
 
-        let cpu_lower = config
-            .get(&Alarm::CpuLowerLimitVcpus)
-            .expect("no lower limit for cpu found");
-        assert_eq!(cpu_lower.value(), 1.);
-        assert_eq!(cpu_lower.period_minutes(), 5);
-        assert_eq!(cpu_lower.data_points(), 6);
-        assert_eq!(cpu_lower.data_points_to_alarm(), 1);
-
-        let cpu_upper = config
-            .get(&Alarm::CpuUpperLimitVcpus)
-            .expect("no upper limit for cpu found");
-        assert_eq!(cpu_upper.value(), 4.);
-        assert_eq!(cpu_upper.period_minutes(), 3);
-        assert_eq!(cpu_upper.data_points(), 2);
-        assert_eq!(cpu_upper.data_points_to_alarm(), 2);
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_float(), Some(1.));
+        assert_eq!(cpu_vcpus.period_minutes(), 5);
+        assert_eq!(cpu_vcpus.data_points(), 6);
+        assert_eq!(cpu_vcpus.data_points_to_alarm(), 1);
+
+        let memory_upper = config
+            .get(&Alarm::MemoryUpperLimitBytes)
+            .expect("no memory upper limit found");
+        assert_eq!(memory_upper.value().as_float(), Some(4.));
+        assert_eq!(memory_upper.period_minutes(), 3);
+        assert_eq!(memory_upper.data_points(), 2);
+        assert_eq!(memory_upper.data_points_to_alarm(), 2);
+
+        std::env::remove_var("MEMORY_UPPER_LIMIT_BYTES");
+
+        // Range
+        // Kept in this env-owning test rather than its own #[test] to avoid racing `all`
+        // over shared process-wide env vars. CPU_VCPUS is the motivating case: one alarm
+        // bounding a metric on both sides instead of a separate lower/upper-limit variant.
+        std::env::set_var("CPU_VCPUS", "0.5..4");
+        let config = super::get().expect("unable to get config from env vars");
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_min_max(), Some((Some(0.5), Some(4.))));
+
+        std::env::set_var("CPU_VCPUS", "..4");
+        let config = super::get().expect("unable to get config from env vars");
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_min_max(), Some((None, Some(4.))));
+
+        std::env::set_var("CPU_VCPUS", "0.5..");
+        let config = super::get().expect("unable to get config from env vars");
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_min_max(), Some((Some(0.5), None)));
+
+        // Backward compatible single-value form still parses as a plain float
+        std::env::set_var("CPU_VCPUS", "4");
+        let config = super::get().expect("unable to get config from env vars");
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_float(), Some(4.));
+
+        // Both sides empty and inverted ranges are rejected rather than silently
+        // producing a band that can never, or always, trip
+        std::env::set_var("CPU_VCPUS", "..");
+        assert!(super::get().is_err());
+
+        std::env::set_var("CPU_VCPUS", "4..0.5");
+        assert!(super::get().is_err());
+
+        std::env::remove_var("CPU_VCPUS");
+
+        // Deprecated aliases still resolve onto CpuVcpus, including their own overrides,
+        // so a deployment still setting the pre-consolidation env vars doesn't lose its alarm
+        std::env::set_var("CPU_LOWER_LIMIT_VCPUS", "0.5");
+        std::env::set_var("CPU_LOWER_LIMIT_VCPUS_DATA_POINTS", "7");
+        let config = super::get().expect("unable to get config from env vars");
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_float(), Some(0.5));
+        assert_eq!(cpu_vcpus.data_points(), 7);
+        std::env::remove_var("CPU_LOWER_LIMIT_VCPUS_DATA_POINTS");
+        std::env::remove_var("CPU_LOWER_LIMIT_VCPUS");
+
+        // The canonical name takes priority if both are set
+        std::env::set_var("CPU_UPPER_LIMIT_VCPUS", "1");
+        std::env::set_var("CPU_VCPUS", "2");
+        let config = super::get().expect("unable to get config from env vars");
+        let cpu_vcpus = config
+            .get(&Alarm::CpuVcpus)
+            .expect("no cpu vcpus alarm found");
+        assert_eq!(cpu_vcpus.value().as_float(), Some(2.));
+        std::env::remove_var("CPU_UPPER_LIMIT_VCPUS");
+        std::env::remove_var("CPU_VCPUS");
+    }
+
+    #[test]
+    fn validate_rejects_zero_window_and_clamps_data_points_to_alarm() {
+        let mut configs = HashMap::new();
+        configs.insert(
+            Alarm::CpuVcpus,
+            AlarmConfig {
+                value: AlarmThreshold::Float(1.),
+                period_minutes: 1,
+                data_points: 0,
+                data_points_to_alarm: 1,
+            },
+        );
+        configs.insert(
+            Alarm::MemoryUpperLimitBytes,
+            AlarmConfig {
+                value: AlarmThreshold::Float(1.),
+                period_minutes: 1,
+                data_points: 2,
+                data_points_to_alarm: 5,
+            },
+        );
+
+        let errors =
+            super::validate(&mut configs).expect_err("zero data_points should be rejected");
+        assert_eq!(errors.len(), 1);
+
+        let memory_upper = configs
+            .get(&Alarm::MemoryUpperLimitBytes)
+            .expect("no memory upper limit found");
+        assert_eq!(memory_upper.data_points_to_alarm(), 2);
     }
 }
@@ -0,0 +1,19 @@
+use thiserror::Error as ThisError;
+
+#[derive(ThisError, Debug)]
+pub enum Error {
+    #[error("missing required env var: {0}")]
+    MissingEnvVar(&'static str),
+    #[error("failed to parse \"{1}\" as an integer: {0}")]
+    ParseIntWithMetadata(std::num::ParseIntError, String),
+    #[error("failed to parse \"{1}\" as a float: {0}")]
+    ParseFloatWithMetadata(std::num::ParseFloatError, String),
+    #[error("{0} has a zero data_points window, which can never satisfy data_points_to_alarm")]
+    ZeroDataPointsWindow(String),
+    #[error("invalid range for {0}: {1}")]
+    InvalidRange(String, &'static str),
+    #[error("invalid configuration: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    Multiple(Vec<Error>),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
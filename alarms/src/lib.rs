@@ -0,0 +1,7 @@
+mod alarm;
+mod config;
+mod error;
+
+pub use alarm::Alarm;
+pub use config::{optional, required, AlarmConfig, AlarmThreshold};
+pub use error::{Error, Result};